@@ -1,3 +1,6 @@
+use std::borrow::{Borrow, Cow};
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::{slice, str};
 
 use ffi;
@@ -8,7 +11,7 @@ use types::LuaRef;
 /// Handle to an internal Lua string.
 ///
 /// Unlike Rust strings, Lua strings may not be valid UTF-8.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct String<'lua>(pub(crate) LuaRef<'lua>);
 
 impl<'lua> String<'lua> {
@@ -42,6 +45,29 @@ impl<'lua> String<'lua> {
         })
     }
 
+    /// Get this string as a `Cow<str>`, converting invalid UTF-8 byte sequences to
+    /// `U+FFFD REPLACEMENT CHARACTER`s.
+    ///
+    /// This is a best-effort conversion for cases like logging or display where a `Result` from
+    /// `to_str` would be inconvenient. Strings that are already valid UTF-8 borrow their data
+    /// directly, and only strings containing invalid sequences allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate rlua;
+    /// # use rlua::{Lua, String};
+    /// # fn main() {
+    /// let lua = Lua::new();
+    ///
+    /// let non_utf8: String = lua.eval(r#"  "test\xff"  "#, None).unwrap();
+    /// assert_eq!(non_utf8.to_string_lossy(), "test\u{fffd}");
+    /// # }
+    /// ```
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        std::string::String::from_utf8_lossy(self.as_bytes())
+    }
+
     /// Get the bytes that make up this string.
     ///
     /// The returned slice will not contain the terminating nul byte, but will contain any nul
@@ -96,6 +122,34 @@ impl<'lua> AsRef<[u8]> for String<'lua> {
     }
 }
 
+// Lua strings are not necessarily valid UTF-8, so we can't just derive `Debug` (which would
+// print the opaque `LuaRef` anyway). Valid UTF-8 strings print like normal Rust strings; anything
+// else prints as an escaped byte string literal.
+impl<'lua> fmt::Debug for String<'lua> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bytes = self.as_bytes();
+        match str::from_utf8(bytes) {
+            Ok(s) => s.fmt(f),
+            Err(_) => {
+                write!(f, "b\"")?;
+                for &byte in bytes {
+                    match byte {
+                        b'\n' => write!(f, "\\n")?,
+                        b'\r' => write!(f, "\\r")?,
+                        b'\t' => write!(f, "\\t")?,
+                        b'\\' => write!(f, "\\\\")?,
+                        b'"' => write!(f, "\\\"")?,
+                        b'\0' => write!(f, "\\0")?,
+                        0x20..=0x7e => write!(f, "{}", byte as char)?,
+                        _ => write!(f, "\\x{:02x}", byte)?,
+                    }
+                }
+                write!(f, "\"")
+            }
+        }
+    }
+}
+
 // Lua strings are basically &[u8] slices, so implement PartialEq for anything resembling that.
 //
 // This makes our `String` comparable with `Vec<u8>`, `[u8]`, `&str`, `String` and `rlua::String`
@@ -112,3 +166,32 @@ where
         self.as_bytes() == other.as_ref()
     }
 }
+
+impl<'lua> Eq for String<'lua> {}
+
+// Hash over the same bytes that `PartialEq`/`Eq` compare, so that `String` can be used as a
+// `HashMap`/`HashSet` key and looked up by `&[u8]` via `Borrow`.
+impl<'lua> Hash for String<'lua> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl<'lua> Borrow<[u8]> for String<'lua> {
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'lua> ::serde::ser::Serialize for String<'lua> {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+    where
+        S: ::serde::ser::Serializer,
+    {
+        match str::from_utf8(self.as_bytes()) {
+            Ok(s) => serializer.serialize_str(s),
+            Err(_) => serializer.serialize_bytes(self.as_bytes()),
+        }
+    }
+}